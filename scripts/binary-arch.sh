@@ -0,0 +1,70 @@
+#!/bin/bash
+
+# Check that a binary's header matches its target triple
+# Usage: binary-arch.sh <binary> <target-triple>
+#
+# The "Verify binary architecture" step of the reusable workflow runs in the
+# caller's repository, so it carries a copy of the functions below between
+# its "scripts/binary-arch.sh" markers. test-binary-arch.sh checks that the
+# copy stays in sync.
+
+set -euo pipefail
+
+# Read a little-endian unsigned integer: read_le <file> <offset> <length>
+read_le() {
+    od -An -tu1 -j "$2" -N "$3" "$1" | awk '{ for (i = NF; i >= 1; i--) v = v * 256 + $i } END { print v + 0 }'
+}
+
+# Print the header format and machine value expected for a triple of the
+# build matrix; prints nothing for triples the table doesn't know
+expected_machine() {
+    case "$1" in
+        x86_64-pc-windows-*) echo "PE $((0x8664))" ;;
+        aarch64-pc-windows-*) echo "PE $((0xAA64))" ;;
+        x86_64-apple-darwin) echo "Mach-O $((0x01000007))" ;;
+        aarch64-apple-darwin) echo "Mach-O $((0x0100000C))" ;;
+        x86_64-unknown-linux-*) echo "ELF 62" ;;
+        aarch64-unknown-linux-*) echo "ELF 183" ;;
+    esac
+}
+
+# Print the machine value from a binary's header: binary_machine <file> <format>
+binary_machine() {
+    local magic
+    magic=$(od -An -tx1 -N 4 "$1" | tr -d ' \n')
+    case "$2" in
+        ELF)
+            [[ "$magic" == "7f454c46" ]] || { echo "Error: $1 is not an ELF binary (magic: $magic)" >&2; return 1; }
+            read_le "$1" 18 2
+            ;;
+        Mach-O)
+            [[ "$magic" == "cffaedfe" ]] || { echo "Error: $1 is not a 64-bit Mach-O binary (magic: $magic)" >&2; return 1; }
+            read_le "$1" 4 4
+            ;;
+        PE)
+            [[ "${magic:0:4}" == "4d5a" ]] || { echo "Error: $1 is not a PE executable (magic: $magic)" >&2; return 1; }
+            read_le "$1" $(($(read_le "$1" 60 4) + 4)) 2
+            ;;
+    esac
+}
+
+if [[ "${BASH_SOURCE[0]}" == "$0" ]]; then
+    if [[ $# -ne 2 ]]; then
+        echo "Usage: $0 <binary> <target-triple>"
+        exit 1
+    fi
+
+    read -r FORMAT EXPECTED <<< "$(expected_machine "$2")"
+    if [[ -z "${EXPECTED:-}" ]]; then
+        echo "Warning: No known machine type for $2, skipping architecture check"
+        exit 0
+    fi
+
+    MACHINE=$(binary_machine "$1" "$FORMAT")
+    printf 'Expected %s machine 0x%X, found 0x%X\n' "$FORMAT" "$EXPECTED" "$MACHINE"
+    if [[ "$MACHINE" -ne "$EXPECTED" ]]; then
+        echo "Error: $1 was built for the wrong architecture (requested $2)"
+        exit 1
+    fi
+    echo "Architecture verified: $1"
+fi
@@ -64,7 +64,11 @@ case "$TARGET" in
         BINARY_EXT=".exe"
         ARCHIVE_EXT="zip"
         if [[ "$TARGET" == *"x86_64"* ]]; then
-            PLATFORM="windows-x86_64"
+            if [[ "$TARGET" == *"gnu"* ]]; then
+                PLATFORM="windows-x86_64-gnu"
+            else
+                PLATFORM="windows-x86_64"
+            fi
         elif [[ "$TARGET" == *"aarch64"* ]]; then
             PLATFORM="windows-arm64"
         elif [[ "$TARGET" == *"i686"* ]]; then
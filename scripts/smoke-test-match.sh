@@ -0,0 +1,41 @@
+#!/bin/bash
+
+# Check a binary's --version output for the smoke test
+# Usage: smoke-test-match.sh <regex> [version] < output
+#
+# The "Smoke test binaries" step of the reusable workflow runs in the
+# caller's repository, so it carries a copy of the functions below between
+# its "scripts/smoke-test-match.sh" markers. test-smoke-test-match.sh checks
+# that the copy stays in sync.
+
+set -euo pipefail
+
+# Succeeds if the Perl regex matches anywhere in the output; ^ and $ match
+# at line boundaries, so banner lines around the version are allowed
+smoke_output_matches() {
+    printf '%s' "$2" | PATTERN="$1" perl -0777 -ne 'exit(/$ENV{PATTERN}/m ? 0 : 1)'
+}
+
+# Succeeds if the output reports the version as a whole token, optionally
+# prefixed with v: 1.2.3 matches "tool v1.2.3" but not 1.2.30 or 1.2.3-rc1
+smoke_output_has_version() {
+    printf '%s' "$2" | EXPECT_VERSION="$1" perl -0777 -ne 'exit(/(?<![0-9A-Za-z.+-])v?\Q$ENV{EXPECT_VERSION}\E(?![0-9A-Za-z+-]|\.[0-9])/ ? 0 : 1)'
+}
+
+if [[ "${BASH_SOURCE[0]}" == "$0" ]]; then
+    if [[ $# -lt 1 ]]; then
+        echo "Usage: $0 <regex> [version] < output"
+        exit 1
+    fi
+
+    OUTPUT=$(cat)
+    if ! smoke_output_matches "$1" "$OUTPUT"; then
+        echo "Error: Output does not match: $1"
+        exit 1
+    fi
+    if [[ -n "${2:-}" ]] && ! smoke_output_has_version "$2" "$OUTPUT"; then
+        echo "Error: Output does not report version $2"
+        exit 1
+    fi
+    echo "Output matches"
+fi
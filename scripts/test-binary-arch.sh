@@ -0,0 +1,103 @@
+#!/bin/bash
+
+# Tests for binary-arch.sh over a table of triples and synthetic headers
+# Usage: test-binary-arch.sh
+
+set -euo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+WORKFLOW="$SCRIPT_DIR/../.github/workflows/rust-release.yml"
+
+# shellcheck source=binary-arch.sh
+source "$SCRIPT_DIR/binary-arch.sh"
+
+TEMP_DIR=$(mktemp -d)
+trap 'rm -rf "$TEMP_DIR"' EXIT
+
+FAILURES=0
+
+check() {
+    local desc="$1" want="$2" got="$3"
+    if [[ "$got" == "$want" ]]; then
+        echo "ok: $desc"
+    else
+        echo "FAIL: $desc (expected '$want', got '$got')"
+        FAILURES=$((FAILURES + 1))
+    fi
+}
+
+# Triple -> expected format and machine value
+while read -r triple want; do
+    check "expected_machine $triple" "$want" "$(expected_machine "$triple")"
+done <<'EOF'
+x86_64-unknown-linux-gnu ELF 62
+x86_64-unknown-linux-musl ELF 62
+aarch64-unknown-linux-gnu ELF 183
+aarch64-unknown-linux-musl ELF 183
+x86_64-apple-darwin Mach-O 16777223
+aarch64-apple-darwin Mach-O 16777228
+x86_64-pc-windows-msvc PE 34404
+x86_64-pc-windows-gnu PE 34404
+aarch64-pc-windows-msvc PE 43620
+i686-unknown-linux-gnu
+s390x-unknown-linux-gnu
+wasm32-unknown-unknown
+EOF
+
+# Every target the build matrix can contain must be in the table, including
+# the opt-in windows-gnu entry
+while read -r triple; do
+    check "matrix target $triple is known" "yes" "$([[ -n "$(expected_machine "$triple")" ]] && echo yes || echo no)"
+done < <(sed -n '/DEFAULT_MATRIX=/,/# Apply exclude filter/p' "$WORKFLOW" | grep -o '"target": *"[^"]*"' | cut -d'"' -f4 | sort -u)
+
+# Synthetic headers: write_header <file> <hex bytes at offset 0> [<offset> <hex bytes>]...
+write_header() {
+    local file="$1"
+    shift
+    head -c 256 /dev/zero > "$file"
+    printf "$(echo "$1" | sed 's/\(..\)/\\x\1/g')" | dd of="$file" conv=notrunc status=none
+    shift
+    while [[ $# -gt 0 ]]; do
+        printf "$(echo "$2" | sed 's/\(..\)/\\x\1/g')" | dd of="$file" bs=1 seek="$1" conv=notrunc status=none
+        shift 2
+    done
+}
+
+write_header "$TEMP_DIR/elf-x86_64" 7f454c46020101 18 3e00
+write_header "$TEMP_DIR/elf-aarch64" 7f454c46020101 18 b700
+write_header "$TEMP_DIR/macho-arm64" cffaedfe0c000001
+write_header "$TEMP_DIR/macho-x86_64" cffaedfe07000001
+write_header "$TEMP_DIR/pe-x86_64" 4d5a 60 80000000 128 504500006486
+write_header "$TEMP_DIR/pe-arm64" 4d5a 60 80000000 128 5045000064aa
+write_header "$TEMP_DIR/not-a-binary" 23212f62696e2f7368
+
+check "ELF x86_64 e_machine" "62" "$(binary_machine "$TEMP_DIR/elf-x86_64" ELF)"
+check "ELF aarch64 e_machine" "183" "$(binary_machine "$TEMP_DIR/elf-aarch64" ELF)"
+check "Mach-O arm64 cputype" "16777228" "$(binary_machine "$TEMP_DIR/macho-arm64" Mach-O)"
+check "Mach-O x86_64 cputype" "16777223" "$(binary_machine "$TEMP_DIR/macho-x86_64" Mach-O)"
+check "PE x86_64 machine" "34404" "$(binary_machine "$TEMP_DIR/pe-x86_64" PE)"
+check "PE arm64 machine" "43620" "$(binary_machine "$TEMP_DIR/pe-arm64" PE)"
+check "ELF magic is required" "failed" "$(binary_machine "$TEMP_DIR/not-a-binary" ELF 2>/dev/null || echo failed)"
+check "PE magic is required" "failed" "$(binary_machine "$TEMP_DIR/elf-x86_64" PE 2>/dev/null || echo failed)"
+
+# End to end through the script's command line
+check "matching binary passes" "0" "$("$SCRIPT_DIR/binary-arch.sh" "$TEMP_DIR/elf-aarch64" aarch64-unknown-linux-gnu > /dev/null; echo $?)"
+check "wrong architecture fails" "1" "$("$SCRIPT_DIR/binary-arch.sh" "$TEMP_DIR/elf-x86_64" aarch64-unknown-linux-gnu > /dev/null; echo $?)"
+check "unknown triple is skipped" "0" "$("$SCRIPT_DIR/binary-arch.sh" "$TEMP_DIR/elf-x86_64" s390x-unknown-linux-gnu > /dev/null; echo $?)"
+
+# The workflow's copy of the functions must match this script's
+SCRIPT_COPY=$(sed -n '/^# Read a little-endian/,/^if \[\[ "\${BASH_SOURCE\[0\]}"/p' "$SCRIPT_DIR/binary-arch.sh" | sed '$d' | sed 's/^ *//' | sed '/^$/d')
+WORKFLOW_COPY=$(sed -n '/# >>> scripts\/binary-arch.sh/,/# <<< scripts\/binary-arch.sh/p' "$WORKFLOW" | sed '1d;$d' | sed 's/^ *//' | sed '/^$/d')
+if [[ "$SCRIPT_COPY" == "$WORKFLOW_COPY" ]]; then
+    echo "ok: workflow copy matches binary-arch.sh"
+else
+    echo "FAIL: workflow copy differs from binary-arch.sh"
+    diff <(echo "$SCRIPT_COPY") <(echo "$WORKFLOW_COPY") || true
+    FAILURES=$((FAILURES + 1))
+fi
+
+if [[ $FAILURES -gt 0 ]]; then
+    echo "Error: $FAILURES test(s) failed"
+    exit 1
+fi
+echo "All tests passed"
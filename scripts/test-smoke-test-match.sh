@@ -0,0 +1,72 @@
+#!/bin/bash
+
+# Tests for smoke-test-match.sh over sample --version outputs
+# Usage: test-smoke-test-match.sh
+
+set -euo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+WORKFLOW="$SCRIPT_DIR/../.github/workflows/rust-release.yml"
+
+# shellcheck source=smoke-test-match.sh
+source "$SCRIPT_DIR/smoke-test-match.sh"
+
+FAILURES=0
+
+# expect <pass|fail> <description> <command...>
+expect() {
+    local want="$1" desc="$2"
+    shift 2
+    local got="fail"
+    if "$@"; then
+        got="pass"
+    fi
+    if [[ "$got" == "$want" ]]; then
+        echo "ok: $desc"
+    else
+        echo "FAIL: $desc (expected $want, got $got)"
+        FAILURES=$((FAILURES + 1))
+    fi
+}
+
+DEFAULT='\d+\.\d+\.\d+'
+BANNER=$'Welcome to my-app!\nmy-app 1.2.3 (abc1234 2026-01-01)\nCopyright (c) example'
+
+# smoke-test-expect
+expect pass "plain clap output" smoke_output_matches "$DEFAULT" "my-app 1.2.3"
+expect pass "banner lines around the version" smoke_output_matches "$DEFAULT" "$BANNER"
+expect fail "no version in output" smoke_output_matches "$DEFAULT" $'Welcome to my-app!\nmy-app'
+expect pass "anchored regex on a later line" smoke_output_matches '^my-app \d' "$BANNER"
+expect fail "anchored regex against the banner" smoke_output_matches '^my-app \d+\.\d+\.\d+$' "$BANNER"
+expect fail "empty output" smoke_output_matches "$DEFAULT" ""
+
+# smoke-test-expect-version
+expect pass "1.2.3 in plain output" smoke_output_has_version "1.2.3" "my-app 1.2.3"
+expect pass "v1.2.3 in output" smoke_output_has_version "1.2.3" "my-app v1.2.3"
+expect pass "version inside a banner" smoke_output_has_version "1.2.3" "$BANNER"
+expect pass "version ending a sentence" smoke_output_has_version "1.2.3" "my-app version 1.2.3."
+expect fail "1.2.30 is not 1.2.3" smoke_output_has_version "1.2.3" "my-app 1.2.30"
+expect fail "11.2.3 is not 1.2.3" smoke_output_has_version "1.2.3" "my-app 11.2.3"
+expect fail "1.2.3.4 is not 1.2.3" smoke_output_has_version "1.2.3" "my-app 1.2.3.4"
+expect fail "1.2.3-rc1 is not 1.2.3" smoke_output_has_version "1.2.3" "my-app 1.2.3-rc1"
+expect pass "1.2.3-rc1 matches itself" smoke_output_has_version "1.2.3-rc1" "my-app 1.2.3-rc1"
+expect fail "1.2.3 is not 1.2.3-rc1" smoke_output_has_version "1.2.3-rc1" "my-app 1.2.3"
+expect fail "dots do not match any character" smoke_output_has_version "1.2.3" "my-app 1x2x3"
+
+# The workflow's copy of the functions must match this script's
+strip() {
+    sed -n '/^ *smoke_output_matches() {/,/^ *}$/p; /^ *smoke_output_has_version() {/,/^ *}$/p' | sed 's/^ *//'
+}
+if diff <(strip < "$SCRIPT_DIR/smoke-test-match.sh") <(strip < "$WORKFLOW") > /dev/null; then
+    echo "ok: workflow copy matches smoke-test-match.sh"
+else
+    echo "FAIL: workflow copy differs from smoke-test-match.sh"
+    diff <(strip < "$SCRIPT_DIR/smoke-test-match.sh") <(strip < "$WORKFLOW") || true
+    FAILURES=$((FAILURES + 1))
+fi
+
+if [[ $FAILURES -gt 0 ]]; then
+    echo "Error: $FAILURES test(s) failed"
+    exit 1
+fi
+echo "All tests passed"